@@ -1,15 +1,28 @@
 use core::time;
-use std::{collections::{HashMap, HashSet}, sync::{Arc, Mutex}, thread, time::{Duration, SystemTime}};
-use rand::Rng;
-use clap::Parser;
+use std::{collections::HashMap, sync::{mpsc, mpsc::{Receiver, Sender}, Arc, Mutex}, thread, time::{Duration, SystemTime}};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use clap::{Parser, ValueEnum};
+
+/// With only one philosopher, `left_chopstick` and `right_chopstick` would be
+/// clones of the same `Arc<Mutex<Chopstick>>`, and the blocking strategies
+/// would deadlock locking it twice from the same thread. Reject that up front.
+fn at_least_two_philosophers(s: &str) -> Result<usize, String> {
+    let n: usize = s.parse().map_err(|e| format!("{e}"))?;
+    if n < 2 {
+        Err("must be at least 2".to_string())
+    } else {
+        Ok(n)
+    }
+}
 
 #[derive(Parser)]
 #[clap(name = "dining-philosophers")]
 #[clap(version = "1.0")]
 #[clap(about = "Simulate the dining philosophers problem.", long_about = None)]
 struct Cli {
-    /// The number of philosphers and chopsticks
-    #[arg(short, long, default_value_t = 5)]
+    /// The number of philosphers and chopsticks (at least 2, so left and right chopsticks are never the same one)
+    #[arg(short, long, default_value_t = 5, value_parser = at_least_two_philosophers)]
     number: usize,
 
     /// Simulation duration (in seconds)
@@ -23,6 +36,29 @@ struct Cli {
     /// Eating max duration (in millis)
     #[arg(short, long, default_value_t = 5000)]
     eat: u64,
+
+    /// Deadlock-resolution strategy used when a philosopher tries to eat
+    #[arg(short, long, value_enum, default_value_t = Strategy::TryLock)]
+    strategy: Strategy,
+
+    /// Seed controlling each philosopher's hunger checks and sleep durations (derives one
+    /// stream per philosopher). This makes those random decisions reproducible, but real
+    /// thread scheduling and sleep timing still vary between runs, so it does not guarantee
+    /// an identical event log.
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Strategy {
+    /// Try to grab both chopsticks without blocking; give up if either is taken
+    TryLock,
+    /// Always lock the lower-id chopstick first, preventing circular wait
+    Hierarchy,
+    /// Serialize chopstick acquisition through a single arbitrator
+    Arbitrator,
+    /// Chandy-Misra: fully distributed, starvation-free fork passing with clean/dirty state
+    ChandyMisra,
 }
 
 #[derive(Debug)]
@@ -41,14 +77,19 @@ struct Philosopher {
     id: usize,
     left_chopstick: Arc<Mutex<Chopstick>>,
     right_chopstick: Arc<Mutex<Chopstick>>,
-    times_fed: Arc<Mutex<u64>>
+    times_fed: Arc<Mutex<u64>>,
+    rng: Arc<Mutex<StdRng>>,
+    start: SystemTime
 }
 
+// Every event carries a timestamp; `analyze` takes the latest one of any
+// kind as the simulation's end. The philosopher id on `Thinking` is not read.
+#[allow(dead_code)]
 #[derive(Debug, Clone)]
 enum Event {
-    Thinking(usize),
-    Eating(usize, usize, usize),
-    FinishedEating(usize, usize, usize)
+    Thinking(usize, Duration),
+    Eating(usize, usize, usize, Duration),
+    FinishedEating(usize, usize, usize, Duration)
 }
 
 impl Philosopher {
@@ -56,63 +97,373 @@ impl Philosopher {
         id: usize,
         left_chopstick: Arc<Mutex<Chopstick>>,
         right_chopstick: Arc<Mutex<Chopstick>>,
+        rng: StdRng,
+        start: SystemTime,
     ) -> Philosopher {
         Philosopher {
             id,
             left_chopstick,
             right_chopstick,
-            times_fed: Arc::new(Mutex::new(0))
+            times_fed: Arc::new(Mutex::new(0)),
+            rng: Arc::new(Mutex::new(rng)),
+            start
         }
     }
 
+    fn elapsed(&self) -> Duration {
+        self.start.elapsed().unwrap_or_default()
+    }
+
     fn think(&self, max_think_duration: u64, events: Arc<Mutex<Vec<Event>>>) {
-        let millis = rand_sleep_duration(max_think_duration);
+        let millis = rand_sleep_duration(max_think_duration, &mut self.rng.lock().unwrap());
         let mut es = events.lock().unwrap();
-        es.push(Event::Thinking(self.id));
+        es.push(Event::Thinking(self.id, self.elapsed()));
         drop(es);
         thread::sleep(millis)
     }
 
     fn eat(&self, max_eat_duration: u64, left_id: usize, right_id: usize, events: Arc<Mutex<Vec<Event>>>) {
-        let millis = rand_sleep_duration(max_eat_duration);
+        let millis = rand_sleep_duration(max_eat_duration, &mut self.rng.lock().unwrap());
         let mut es = events.lock().unwrap();
-        es.push(Event::Eating(self.id, left_id, right_id));
+        es.push(Event::Eating(self.id, left_id, right_id, self.elapsed()));
+        drop(es);
+        thread::sleep(millis);
+        let mut t = self.times_fed.lock().unwrap();
+        *t += 1;
+        drop(t);
+        let mut es = events.lock().unwrap();
+        es.push(Event::FinishedEating(self.id, left_id, right_id, self.elapsed()));
         drop(es);
-        thread::sleep(millis)
     }
 
     fn is_hungry(&self) -> bool {
-        let mut rng = rand::rng();
+        let mut rng = self.rng.lock().unwrap();
         rng.random_bool(0.5)
     }
+}
+
+/// A pluggable policy for how a hungry philosopher acquires chopsticks.
+trait EatingStrategy {
+    fn try_to_eat(&self, philosopher: &Philosopher, max_eat_duration: u64, events: Arc<Mutex<Vec<Event>>>);
+}
 
-    fn try_to_eat(&self, max_eat_duration: u64, events: Arc<Mutex<Vec<Event>>>) {
-        let locked_left = self.left_chopstick.try_lock();
-        let locked_right = self.right_chopstick.try_lock();
+/// The original behavior: a non-blocking attempt on both chopsticks that
+/// backs off immediately if either is already taken. Deadlock-free but
+/// prone to starvation.
+struct TryLockStrategy;
+
+impl EatingStrategy for TryLockStrategy {
+    fn try_to_eat(&self, philosopher: &Philosopher, max_eat_duration: u64, events: Arc<Mutex<Vec<Event>>>) {
+        let locked_left = philosopher.left_chopstick.try_lock();
+        let locked_right = philosopher.right_chopstick.try_lock();
 
         if let Ok(left_guard) = locked_left {
             if let Ok(right_guard) = locked_right {
                 let left_id = left_guard.id;
                 let right_id = right_guard.id;
-                self.eat(max_eat_duration, left_id, right_id, events.clone());
-                let mut t = self.times_fed.lock().unwrap();
-                *t += 1;
-                let mut es = events.lock().unwrap();
-                es.push(Event::FinishedEating(self.id, left_id, right_id));
+                philosopher.eat(max_eat_duration, left_id, right_id, events);
                 drop(right_guard);
                 drop(left_guard);
-                drop(es);
             }
         }
     }
 }
 
-fn rand_sleep_duration(max_millis: u64) -> time::Duration {
-    let mut rng = rand::rng();
+/// Resource-hierarchy (lowest-id-first) locking. Every philosopher locks
+/// their lower-id chopstick before their higher-id one, so circular wait
+/// can never form and every philosopher eventually eats.
+struct HierarchyStrategy;
+
+impl EatingStrategy for HierarchyStrategy {
+    fn try_to_eat(&self, philosopher: &Philosopher, max_eat_duration: u64, events: Arc<Mutex<Vec<Event>>>) {
+        let left_id = philosopher.left_chopstick.lock().unwrap().id;
+        let right_id = philosopher.right_chopstick.lock().unwrap().id;
+
+        let (first, second) = if left_id < right_id {
+            (&philosopher.left_chopstick, &philosopher.right_chopstick)
+        } else {
+            (&philosopher.right_chopstick, &philosopher.left_chopstick)
+        };
+
+        let first_guard = first.lock().unwrap();
+        let second_guard = second.lock().unwrap();
+        philosopher.eat(max_eat_duration, left_id, right_id, events);
+        drop(second_guard);
+        drop(first_guard);
+    }
+}
+
+/// A single arbitrator (the classic "waiter") that only lets one
+/// philosopher pick up chopsticks at a time. Once both chopsticks are in
+/// hand the arbitrator is released, so philosophers can still eat
+/// concurrently; deadlock is impossible because only the arbitrator's holder
+/// can wait on a chopstick while holding another, and whoever holds the
+/// chopstick it waits on already has both and is eating.
+struct ArbitratorStrategy {
+    arbitrator: Arc<Mutex<()>>,
+}
+
+impl ArbitratorStrategy {
+    fn new() -> ArbitratorStrategy {
+        ArbitratorStrategy { arbitrator: Arc::new(Mutex::new(())) }
+    }
+}
+
+impl EatingStrategy for ArbitratorStrategy {
+    fn try_to_eat(&self, philosopher: &Philosopher, max_eat_duration: u64, events: Arc<Mutex<Vec<Event>>>) {
+        let permit = self.arbitrator.lock().unwrap();
+        let left_guard = philosopher.left_chopstick.lock().unwrap();
+        let right_guard = philosopher.right_chopstick.lock().unwrap();
+        drop(permit);
+
+        let left_id = left_guard.id;
+        let right_id = right_guard.id;
+        philosopher.eat(max_eat_duration, left_id, right_id, events);
+        drop(right_guard);
+        drop(left_guard);
+    }
+}
+
+fn eating_strategy(strategy: Strategy) -> Arc<dyn EatingStrategy + Send + Sync> {
+    match strategy {
+        Strategy::TryLock => Arc::new(TryLockStrategy),
+        Strategy::Hierarchy => Arc::new(HierarchyStrategy),
+        Strategy::Arbitrator => Arc::new(ArbitratorStrategy::new()),
+        Strategy::ChandyMisra => unreachable!("chandy-misra runs its own simulation loop, see run_chandy_misra"),
+    }
+}
+
+fn rand_sleep_duration(max_millis: u64, rng: &mut StdRng) -> time::Duration {
     let interval = rng.random_range(1..=max_millis);
     time::Duration::from_millis(interval)
 }
 
+/// A message exchanged between neighboring philosophers over the
+/// Chandy-Misra fork channels: ask for a fork, or hand one over (clean,
+/// since a fork is always cleaned before it is relinquished).
+#[derive(Debug, Clone, Copy)]
+enum ForkMsg {
+    Request,
+    Grant { dirty: bool },
+}
+
+/// Each fork starts dirty in the hands of the lower-id philosopher on its
+/// edge; the wrap-around edge (n-1, 0) also resolves to philosopher 0.
+fn cm_fork_owner(fork: usize, n: usize) -> usize {
+    if fork == n - 1 { 0 } else { fork }
+}
+
+/// A philosopher under the Chandy-Misra protocol. Forks are not shared
+/// `Arc<Mutex<Chopstick>>`s here: each one is owned by at most one
+/// philosopher at a time and moves between neighbors over a per-edge
+/// `mpsc` channel, carrying its clean/dirty state with it.
+struct CmPhilosopher {
+    id: usize,
+    left_fork: usize,
+    right_fork: usize,
+    rng: StdRng,
+    start: SystemTime,
+    has_left: bool,
+    has_right: bool,
+    left_dirty: bool,
+    right_dirty: bool,
+    left_requested: bool,
+    right_requested: bool,
+    left_request_pending: bool,
+    right_request_pending: bool,
+    to_left: Sender<ForkMsg>,
+    to_right: Sender<ForkMsg>,
+    from_left: Receiver<ForkMsg>,
+    from_right: Receiver<ForkMsg>,
+}
+
+impl CmPhilosopher {
+    fn elapsed(&self) -> Duration {
+        self.start.elapsed().unwrap_or_default()
+    }
+
+    fn think(&mut self, max_think_duration: u64, events: &Arc<Mutex<Vec<Event>>>) {
+        let millis = rand_sleep_duration(max_think_duration, &mut self.rng);
+        events.lock().unwrap().push(Event::Thinking(self.id, self.elapsed()));
+        thread::sleep(millis)
+    }
+
+    fn is_hungry(&mut self) -> bool {
+        self.rng.random_bool(0.5)
+    }
+
+    fn eat(&mut self, max_eat_duration: u64, events: &Arc<Mutex<Vec<Event>>>) {
+        let millis = rand_sleep_duration(max_eat_duration, &mut self.rng);
+        events.lock().unwrap().push(Event::Eating(self.id, self.left_fork, self.right_fork, self.elapsed()));
+        thread::sleep(millis);
+        self.left_dirty = true;
+        self.right_dirty = true;
+        events.lock().unwrap().push(Event::FinishedEating(self.id, self.left_fork, self.right_fork, self.elapsed()));
+    }
+
+    /// Send a request for any fork we don't currently hold.
+    fn request_missing_forks(&mut self) {
+        if !self.has_left && !self.left_requested {
+            self.to_left.send(ForkMsg::Request).ok();
+            self.left_requested = true;
+        }
+        if !self.has_right && !self.right_requested {
+            self.to_right.send(ForkMsg::Request).ok();
+            self.right_requested = true;
+        }
+    }
+
+    /// A fork we're holding becomes free to relinquish once it's dirty;
+    /// hand it straight over if a neighbor is already waiting on it.
+    fn release_pending(&mut self) {
+        if self.left_request_pending && self.has_left && self.left_dirty {
+            self.has_left = false;
+            self.left_dirty = false;
+            self.left_request_pending = false;
+            self.to_left.send(ForkMsg::Grant { dirty: false }).ok();
+        }
+        if self.right_request_pending && self.has_right && self.right_dirty {
+            self.has_right = false;
+            self.right_dirty = false;
+            self.right_request_pending = false;
+            self.to_right.send(ForkMsg::Grant { dirty: false }).ok();
+        }
+    }
+
+    /// Drain and react to every message waiting on either edge without blocking.
+    fn drain_messages(&mut self) {
+        while let Ok(msg) = self.from_left.try_recv() {
+            match msg {
+                ForkMsg::Request => {
+                    if self.has_left && self.left_dirty {
+                        self.has_left = false;
+                        self.left_dirty = false;
+                        self.to_left.send(ForkMsg::Grant { dirty: false }).ok();
+                    } else if self.has_left {
+                        self.left_request_pending = true;
+                    }
+                },
+                ForkMsg::Grant { dirty } => {
+                    self.has_left = true;
+                    self.left_dirty = dirty;
+                    self.left_requested = false;
+                },
+            }
+        }
+        while let Ok(msg) = self.from_right.try_recv() {
+            match msg {
+                ForkMsg::Request => {
+                    if self.has_right && self.right_dirty {
+                        self.has_right = false;
+                        self.right_dirty = false;
+                        self.to_right.send(ForkMsg::Grant { dirty: false }).ok();
+                    } else if self.has_right {
+                        self.right_request_pending = true;
+                    }
+                },
+                ForkMsg::Grant { dirty } => {
+                    self.has_right = true;
+                    self.right_dirty = dirty;
+                    self.right_requested = false;
+                },
+            }
+        }
+    }
+
+    fn run(mut self, cli: &Cli, timeout: Duration, events: Arc<Mutex<Vec<Event>>>) {
+        loop {
+            if self.elapsed() >= timeout {
+                break;
+            }
+            self.drain_messages();
+            self.release_pending();
+            self.think(cli.think, &events);
+            self.drain_messages();
+            if self.is_hungry() {
+                self.request_missing_forks();
+                while !(self.has_left && self.has_right) {
+                    if self.elapsed() >= timeout {
+                        return;
+                    }
+                    thread::sleep(Duration::from_millis(1));
+                    self.drain_messages();
+                    self.request_missing_forks();
+                }
+                self.eat(cli.eat, &events);
+                self.drain_messages();
+                self.release_pending();
+            }
+        }
+    }
+}
+
+/// Wire up the ring of per-edge channels and run the Chandy-Misra
+/// simulation; returns the same `Event` log the other strategies produce
+/// so it can be handed to the same `analyze`.
+fn run_chandy_misra(cli: &Cli) -> Vec<Event> {
+    let n = cli.number;
+    let timeout = Duration::new(cli.duration, 0);
+    let now = SystemTime::now();
+    let events: Arc<Mutex<Vec<Event>>> = Arc::new(Mutex::new(Vec::new()));
+
+    // Edge k carries the fork shared by philosopher k (as its right fork)
+    // and philosopher (k + 1) % n (as its left fork).
+    let mut to_right: Vec<Option<Sender<ForkMsg>>> = Vec::with_capacity(n);
+    let mut from_left: Vec<Option<Receiver<ForkMsg>>> = Vec::with_capacity(n);
+    let mut to_left: Vec<Option<Sender<ForkMsg>>> = Vec::with_capacity(n);
+    let mut from_right: Vec<Option<Receiver<ForkMsg>>> = Vec::with_capacity(n);
+
+    for _ in 0..n {
+        let (tx_r, rx_r) = mpsc::channel();
+        to_right.push(Some(tx_r));
+        from_left.push(Some(rx_r));
+        let (tx_l, rx_l) = mpsc::channel();
+        to_left.push(Some(tx_l));
+        from_right.push(Some(rx_l));
+    }
+
+    let mut philosophers: Vec<CmPhilosopher> = Vec::with_capacity(n);
+    for i in 0..n {
+        let right_fork = i;
+        let left_fork = (i + n - 1) % n;
+        let rng = match cli.seed {
+            Some(seed) => StdRng::seed_from_u64(seed ^ i as u64),
+            None => StdRng::from_os_rng(),
+        };
+        philosophers.push(CmPhilosopher {
+            id: i,
+            left_fork,
+            right_fork,
+            rng,
+            start: now,
+            has_left: cm_fork_owner(left_fork, n) == i,
+            has_right: cm_fork_owner(right_fork, n) == i,
+            left_dirty: true,
+            right_dirty: true,
+            left_requested: false,
+            right_requested: false,
+            left_request_pending: false,
+            right_request_pending: false,
+            to_left: to_left[left_fork].take().unwrap(),
+            to_right: to_right[right_fork].take().unwrap(),
+            from_left: from_left[left_fork].take().unwrap(),
+            from_right: from_right[right_fork].take().unwrap(),
+        });
+    }
+
+    println!("Simulating (Chandy-Misra)....");
+
+    thread::scope(|scope| {
+        for p in philosophers {
+            let events = events.clone();
+            scope.spawn(move || p.run(cli, timeout, events));
+        }
+    });
+
+    let recorded = events.lock().unwrap().to_vec();
+    recorded
+}
+
 fn n_chopsticks(n: usize) -> Vec<Arc<Mutex<Chopstick>>> {
     let mut out = Vec::new();
     for i in 0..n {
@@ -121,43 +472,118 @@ fn n_chopsticks(n: usize) -> Vec<Arc<Mutex<Chopstick>>> {
     out
 }
 
+/// A completed eating session's occupancy of a single chopstick, bounded
+/// by the real `Eating`/`FinishedEating` timestamps rather than by where
+/// the events happened to land in the shared event log.
+struct EatInterval {
+    philosopher: usize,
+    chopstick: usize,
+    start: Duration,
+    end: Duration,
+}
+
+/// A philosopher whose eat count falls below this fraction of the mean is
+/// flagged as starved.
+const STARVATION_THRESHOLD: f64 = 0.5;
+
+/// Jain's fairness index over the per-philosopher eat counts: 1/n (one
+/// philosopher hogs everything) up to 1.0 (everyone ate equally often).
+fn jain_fairness_index(counts: &[u64]) -> f64 {
+    let n = counts.len() as f64;
+    let sum: u64 = counts.iter().sum();
+    let sum_sq: u64 = counts.iter().map(|c| c * c).sum();
+    if sum_sq == 0 {
+        1.0
+    } else {
+        (sum as f64).powi(2) / (n * sum_sq as f64)
+    }
+}
+
+fn event_timestamp(event: &Event) -> Duration {
+    match event {
+        Event::Thinking(_, ts) => *ts,
+        Event::Eating(_, _, _, ts) => *ts,
+        Event::FinishedEating(_, _, _, ts) => *ts,
+    }
+}
+
+/// Longest stretch between the ends of a philosopher's consecutive meals
+/// (so it includes the later meal's eating time), also bounded by the
+/// simulation's start and end — so a
+/// philosopher who never ate, or ate once and then stalled, is charged
+/// for the full idle stretch instead of reporting a misleading zero gap.
+fn longest_gap(finish_times: &[Duration], sim_end: Duration) -> Duration {
+    let mut bounds = Vec::with_capacity(finish_times.len() + 2);
+    bounds.push(Duration::ZERO);
+    bounds.extend_from_slice(finish_times);
+    bounds.push(sim_end);
+    bounds.sort();
+    bounds.windows(2).map(|w| w[1] - w[0]).max().unwrap_or(Duration::ZERO)
+}
+
 fn analyze(events: Vec<Event>, n: usize) {
     println!("Analyzing...");
 
-    let mut currently_eating: HashSet<(usize, usize, usize)> = HashSet::new();
-    let mut currently_thinking: HashSet<usize> = HashSet::new();
-    let mut discrepancies: Vec<(usize, usize, usize)> = Vec::new();
+    let mut open: HashMap<(usize, usize, usize), Duration> = HashMap::new();
+    let mut intervals: Vec<EatInterval> = Vec::new();
     let mut times_fed: HashMap<usize, u64> = HashMap::new();
+    let mut finish_times: HashMap<usize, Vec<Duration>> = HashMap::new();
 
-    for (i, e) in events.iter().enumerate() {
+    for e in events.iter() {
         match e {
-            Event::Thinking(v) => {
-                currently_thinking.insert(*v);
+            Event::Thinking(_, _) => {},
+            Event::Eating(v, left, right, ts) => {
+                open.insert((*v, *left, *right), *ts);
             },
-            Event::Eating(v, left, right) => {
-                for (p, eating_left, eating_right) in currently_eating.iter() {
-                    if left == eating_left ||
-                       left == eating_right ||
-                       right == eating_left ||
-                       right == eating_right {
-                           discrepancies.push((i, *v, *p));
-                       }
+            Event::FinishedEating(v, left, right, ts) => {
+                if let Some(start) = open.remove(&(*v, *left, *right)) {
+                    intervals.push(EatInterval { philosopher: *v, chopstick: *left, start, end: *ts });
+                    intervals.push(EatInterval { philosopher: *v, chopstick: *right, start, end: *ts });
                 }
-                currently_thinking.remove(v);
-                currently_eating.insert((*v, *left, *right));
-            },
-            Event::FinishedEating(v, left, right) => {
-                currently_eating.remove(&(*v, *left, *right));
                 times_fed.entry(*v).and_modify(|x| *x += 1).or_insert(1);
+                finish_times.entry(*v).or_default().push(*ts);
             }
         }
     }
 
+    let mut by_chopstick: HashMap<usize, Vec<&EatInterval>> = HashMap::new();
+    for iv in intervals.iter() {
+        by_chopstick.entry(iv.chopstick).or_default().push(iv);
+    }
+
+    let mut discrepancies: Vec<(usize, usize, usize)> = Vec::new();
+    for (chopstick, ivs) in by_chopstick.iter() {
+        for i in 0..ivs.len() {
+            for j in (i + 1)..ivs.len() {
+                let a = ivs[i];
+                let b = ivs[j];
+                if a.philosopher != b.philosopher && a.start < b.end && b.start < a.end {
+                    discrepancies.push((*chopstick, a.philosopher, b.philosopher));
+                }
+            }
+        }
+    }
+
+    let counts: Vec<u64> = (0..n).map(|i| *times_fed.get(&i).unwrap_or(&0)).collect();
+    let total: u64 = counts.iter().sum();
+    let mean = total as f64 / n as f64;
+    let fairness = jain_fairness_index(&counts);
+    let sim_end = events.iter().map(event_timestamp).max().unwrap_or(Duration::ZERO);
+    let no_meals: Vec<Duration> = Vec::new();
+
     for i in 0..n {
-        println!("{} ate {} times", i, times_fed.get(&i).or(Some(&0)).unwrap());
+        let count = *times_fed.get(&i).unwrap_or(&0);
+        let gap = longest_gap(finish_times.get(&i).unwrap_or(&no_meals), sim_end);
+        let starved = (count as f64) < mean * STARVATION_THRESHOLD;
+        println!(
+            "{} ate {} times, longest gap between meals {:?}{}",
+            i, count, gap, if starved { " (starved)" } else { "" }
+        );
     }
 
-    if discrepancies.len() == 0 {
+    println!("Jain's fairness index: {:.3} (1.0 is perfectly fair, {:.3} is the worst possible for {} philosophers)", fairness, 1.0 / n as f64, n);
+
+    if discrepancies.is_empty() {
         println!("Simulation correct");
     } else {
         println!("The following discrepancies were found:");
@@ -171,27 +597,42 @@ fn main() {
 
     let cli = Cli::parse();
     let n: usize = cli.number;
+
+    if cli.strategy == Strategy::ChandyMisra {
+        let events = run_chandy_misra(&cli);
+        analyze(events, n);
+        return;
+    }
+
     let chopsticks = n_chopsticks(n);
+    let timeout = Duration::new(cli.duration, 0);
+    let now = SystemTime::now();
+
     let mut philosophers: Vec<Philosopher> = Vec::new();
     for i in 0..n {
+        let rng = match cli.seed {
+            Some(seed) => StdRng::seed_from_u64(seed ^ i as u64),
+            None => StdRng::from_os_rng(),
+        };
         philosophers.push(
             Philosopher::new(
                 i,
                 chopsticks[(n - 1 + i) % n].clone(),
                 chopsticks[i].clone(),
+                rng,
+                now,
             ));
     }
 
     let events = Arc::new(Mutex::new(Vec::new()));
-
-    let timeout = Duration::new(cli.duration, 0);
-    let now = SystemTime::now();
+    let strategy = eating_strategy(cli.strategy);
 
     println!("Simulating....");
 
     thread::scope (|scope| {
         for p in philosophers {
             let events = events.clone();
+            let strategy = strategy.clone();
             scope.spawn(move || {
                 loop {
                     if let Ok(elapsed) = now.elapsed() {
@@ -204,7 +645,7 @@ fn main() {
                     }
                     p.think(cli.think, events.clone());
                     if p.is_hungry() {
-                        p.try_to_eat(cli.eat, events.clone());
+                        strategy.try_to_eat(&p, cli.eat, events.clone());
                     }
                 }
             });